@@ -0,0 +1,84 @@
+use crate::api::types::{
+    auth::{AuthResponse, LoginInput, RegisterInput},
+    responses::ApiResponse,
+};
+use crate::config::Config;
+use crate::db::{get_connection, DbPool};
+use crate::error::common::validation::ValidationError;
+use crate::errors::AppResult;
+use crate::middleware::auth::issue_token;
+use crate::middleware::request_tracing::blocking;
+use crate::services::auth::AuthService;
+use actix_web::{web, HttpResponse};
+use validator::Validate;
+
+pub mod register {
+    use super::*;
+
+    #[utoipa::path(
+        post,
+        path = "/v1/auth/register",
+        request_body = RegisterInput,
+        responses(
+            (status = 201, description = "User registered", body = AuthResponse),
+            (status = 400, description = "Bad request"),
+            (status = 500, description = "Internal server error")
+        )
+    )]
+    pub async fn register(
+        pool: web::Data<DbPool>,
+        config: web::Data<Config>,
+        input: web::Json<RegisterInput>,
+    ) -> AppResult<HttpResponse> {
+        input.validate().map_err(ValidationError::from_validator)?;
+
+        let input = input.into_inner();
+        let user = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            AuthService::register(&mut conn, input)
+        })
+        .await?;
+        let token = issue_token(&user, &config)?;
+
+        Ok(HttpResponse::Created().json(ApiResponse::new(
+            AuthResponse { token },
+            None,
+            "success",
+        )))
+    }
+}
+
+pub mod login {
+    use super::*;
+
+    #[utoipa::path(
+        post,
+        path = "/v1/auth/login",
+        request_body = LoginInput,
+        responses(
+            (status = 200, description = "Authenticated", body = AuthResponse),
+            (status = 401, description = "Invalid credentials"),
+            (status = 500, description = "Internal server error")
+        )
+    )]
+    pub async fn login(
+        pool: web::Data<DbPool>,
+        config: web::Data<Config>,
+        input: web::Json<LoginInput>,
+    ) -> AppResult<HttpResponse> {
+        input.validate().map_err(ValidationError::from_validator)?;
+
+        let input = input.into_inner();
+        let token = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            AuthService::login(&mut conn, input, &config)
+        })
+        .await?;
+
+        Ok(HttpResponse::Ok().json(ApiResponse::new(
+            AuthResponse { token },
+            None,
+            "success",
+        )))
+    }
+}
@@ -1,9 +1,9 @@
 use crate::api::types::{
     organization::{
-        CreateOrganizationInput, ListOrganizationsQuery, OrganizationResponse,
-        UpdateOrganizationInput,
+        CreateOrganizationInput, ListOrganizationsQuery, OrganizationHistoryQuery,
+        OrganizationResponse, UpdateOrganizationInput,
     },
-    pagination::{PaginatedResponse, PaginationParams},
+    pagination::{CursorPaginationParams, PaginatedResponse, PaginationParams, SortOrder},
     responses::ApiResponse,
 };
 use crate::db::{get_connection, models::Organization, DbPool};
@@ -11,8 +11,19 @@ use crate::db::{get_connection, models::Organization, DbPool};
 use crate::errors::AppResult;
 use crate::services::organization::OrganizationService;
 use actix_web::{web, HttpResponse};
-use log::{debug, info};
+use tracing::{debug, info};
 use uuid::Uuid;
+use validator::Validate;
+
+use crate::db::models::audit::AuditEntry;
+use crate::db::models::user::Role;
+use crate::error::common::validation::ValidationError;
+use crate::middleware::auth::AuthedUser;
+use crate::middleware::request_tracing::blocking;
+use crate::services::audit::AuditService;
+
+/// Entity type recorded against audit rows for organization mutations.
+const ORGANIZATION_ENTITY_TYPE: &str = "organization";
 
 pub mod read {
     use super::*;
@@ -38,10 +49,13 @@ pub mod read {
             organization_id
         );
 
-        let mut conn = get_connection(&pool)?;
         let org_id = *organization_id;
 
-        let organization = OrganizationService::find_by_id(&mut conn, org_id)?;
+        let organization = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            OrganizationService::find_by_id(&mut conn, org_id)
+        })
+        .await?;
 
         info!("Retrieved organization: {}", organization.id);
         Ok(HttpResponse::Ok().json(ApiResponse::new(
@@ -63,7 +77,11 @@ pub mod read {
         ),
         params(
             ("limit" = Option<i64>, Query, description = "Limit the number of organizations"),
-            ("offset" = Option<i64>, Query, description = "Offset for pagination")
+            ("offset" = Option<i64>, Query, description = "Offset for pagination"),
+            ("after" = Option<String>, Query, description = "Keyset cursor from a previous page's next_cursor; when set, a cursor-paginated response is returned and offset/search/sort_by are ignored"),
+            ("search" = Option<String>, Query, description = "Search term matched against organization name"),
+            ("sort_by" = Option<String>, Query, description = "Column to sort by"),
+            ("order" = Option<SortOrder>, Query, description = "Sort direction")
         )
     )]
     pub async fn list_organizations(
@@ -71,18 +89,44 @@ pub mod read {
         query: web::Query<ListOrganizationsQuery>,
     ) -> AppResult<HttpResponse> {
         let limit = query.limit.unwrap_or(10);
+
+        if let Some(after) = query.after.clone() {
+            let cursor_pagination = CursorPaginationParams {
+                after: Some(after),
+                per_page: limit,
+            };
+
+            let page = blocking(move || {
+                let mut conn = get_connection(&pool)?;
+                OrganizationService::list_keyset(&mut conn, &cursor_pagination)
+            })
+            .await?;
+
+            info!("Retrieved {} organizations (keyset)", page.data.len());
+            return Ok(HttpResponse::Ok().json(ApiResponse::new(page, None, "success")));
+        }
+
         let offset = query.offset.unwrap_or(0);
         let page = (offset / limit) + 1;
 
-        let mut conn = get_connection(&pool)?;
-
         let pagination = PaginationParams {
             page,
             per_page: limit,
         };
+        let filter = query.into_inner().filter;
+        let block_pagination = pagination.clone();
 
-        let organizations = OrganizationService::list(&mut conn, &pagination)?;
-        let total = organizations.len() as i64;
+        let (organizations, total) = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            if filter.search.is_some() || filter.sort_by.is_some() || filter.order.is_some() {
+                OrganizationService::search(&mut conn, &block_pagination, &filter)
+            } else {
+                let organizations = OrganizationService::list(&mut conn, &block_pagination)?;
+                let total = organizations.len() as i64;
+                Ok((organizations, total))
+            }
+        })
+        .await?;
 
         info!("Retrieved {} organizations", organizations.len());
         Ok(HttpResponse::Ok().json(ApiResponse::new(
@@ -103,16 +147,30 @@ pub mod create {
         responses(
             (status = 201, description = "Organization created", body = OrganizationResponse),
             (status = 400, description = "Bad request"),
+            (status = 403, description = "Forbidden"),
             (status = 500, description = "Internal server error")
         )
     )]
     pub async fn create_organization(
         pool: web::Data<DbPool>,
+        user: AuthedUser,
         new_organization: web::Json<CreateOrganizationInput>,
     ) -> AppResult<HttpResponse> {
-        let mut conn = get_connection(&pool)?;
+        user.require_role(Role::Admin)?;
+
+        new_organization
+            .validate()
+            .map_err(ValidationError::from_validator)?;
+
+        let input = new_organization.into_inner();
+
+        let actor_id = user.user_id;
 
-        let organization = OrganizationService::create(&mut conn, new_organization.into_inner())?;
+        let organization = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            OrganizationService::create(&mut conn, input, Some(actor_id))
+        })
+        .await?;
 
         Ok(HttpResponse::Created().json(ApiResponse::new(
             OrganizationResponse {
@@ -134,6 +192,7 @@ pub mod update {
         responses(
             (status = 200, description = "Organization updated", body = OrganizationResponse),
             (status = 400, description = "Bad request"),
+            (status = 403, description = "Forbidden"),
             (status = 404, description = "Organization not found"),
             (status = 500, description = "Internal server error")
         ),
@@ -143,19 +202,30 @@ pub mod update {
     )]
     pub async fn update_organization(
         pool: web::Data<DbPool>,
+        user: AuthedUser,
         organization_id: web::Path<Uuid>,
         updated_organization: web::Json<UpdateOrganizationInput>,
     ) -> AppResult<HttpResponse> {
+        user.require_role(Role::Admin)?;
+
+        updated_organization
+            .validate()
+            .map_err(ValidationError::from_validator)?;
+
         debug!(
             "Attempting to update organization with id: {}",
             organization_id
         );
 
-        let mut conn = get_connection(&pool)?;
         let org_id = *organization_id;
+        let input = updated_organization.into_inner();
+        let actor_id = user.user_id;
 
-        let organization =
-            OrganizationService::update(&mut conn, org_id, updated_organization.into_inner())?;
+        let organization = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            OrganizationService::update(&mut conn, org_id, input, Some(actor_id))
+        })
+        .await?;
 
         info!("Updated organization: {}", organization.id);
         Ok(HttpResponse::Ok().json(ApiResponse::new(
@@ -176,6 +246,7 @@ pub mod delete {
         path = "/v1/organizations/{id}",
         responses(
             (status = 204, description = "Organization deleted"),
+            (status = 403, description = "Forbidden"),
             (status = 404, description = "Organization not found"),
             (status = 500, description = "Internal server error")
         ),
@@ -185,19 +256,77 @@ pub mod delete {
     )]
     pub async fn delete_organization(
         pool: web::Data<DbPool>,
+        user: AuthedUser,
         organization_id: web::Path<Uuid>,
     ) -> AppResult<HttpResponse> {
+        user.require_role(Role::Admin)?;
+
         debug!(
             "Attempting to delete organization with id: {}",
             organization_id
         );
 
-        let mut conn = get_connection(&pool)?;
         let org_id = *organization_id;
+        let actor_id = user.user_id;
 
-        OrganizationService::delete(&mut conn, org_id)?;
+        blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            OrganizationService::delete(&mut conn, org_id, Some(actor_id))
+        })
+        .await?;
 
         info!("Deleted organization: {}", org_id);
         Ok(HttpResponse::NoContent().finish())
     }
 }
+
+pub mod history {
+    use super::*;
+
+    #[utoipa::path(
+        get,
+        path = "/v1/organizations/{id}/history",
+        responses(
+            (status = 200, description = "Paginated audit history", body = PaginatedResponse<AuditEntry>),
+            (status = 400, description = "Bad request"),
+            (status = 500, description = "Internal server error")
+        ),
+        params(
+            ("id" = Uuid, Path, description = "Organization ID"),
+            ("limit" = Option<i64>, Query, description = "Limit the number of entries"),
+            ("offset" = Option<i64>, Query, description = "Offset for pagination")
+        )
+    )]
+    pub async fn get_organization_history(
+        pool: web::Data<DbPool>,
+        organization_id: web::Path<Uuid>,
+        query: web::Query<OrganizationHistoryQuery>,
+    ) -> AppResult<HttpResponse> {
+        let org_id = *organization_id;
+        let limit = query.limit.unwrap_or(10);
+        let offset = query.offset.unwrap_or(0);
+        let pagination = PaginationParams {
+            page: (offset / limit) + 1,
+            per_page: limit,
+        };
+        let block_pagination = pagination.clone();
+
+        let (entries, total) = blocking(move || {
+            let mut conn = get_connection(&pool)?;
+            AuditService::history_for_entity(
+                &mut conn,
+                ORGANIZATION_ENTITY_TYPE,
+                org_id,
+                &block_pagination,
+            )
+        })
+        .await?;
+
+        info!("Retrieved {} audit entries for organization {}", entries.len(), org_id);
+        Ok(HttpResponse::Ok().json(ApiResponse::new(
+            PaginatedResponse::new(entries, total, &pagination),
+            None,
+            "success",
+        )))
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use super::pagination::FilterParams;
+use crate::db::models::Organization;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    pub organization: Organization,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateOrganizationInput {
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateOrganizationInput {
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListOrganizationsQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// Opaque keyset cursor from a previous page's `next_cursor`. When
+    /// present, the endpoint serves a cursor-paginated response instead of
+    /// the offset-paginated one, and `offset`/`search`/`sort_by` are ignored.
+    pub after: Option<String>,
+    #[serde(flatten)]
+    pub filter: FilterParams,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OrganizationHistoryQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// Columns `sort_by` may target; see
+/// [`validate_sort_column`](crate::db::repositories::base::validate_sort_column).
+pub const ORGANIZATION_SORTABLE_COLUMNS: &[&str] = &["name", "created_at", "updated_at"];
+pub const ORGANIZATION_SEARCHABLE_COLUMNS: &[&str] = &["name"];
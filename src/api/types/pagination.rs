@@ -0,0 +1,30 @@
+pub use crate::db::repositories::base::{
+    CursorPaginatedResponse, CursorPaginationParams, PaginatedResponse, PaginationMeta,
+    PaginationParams,
+};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Asc
+    }
+}
+
+/// Search/sort parameters shared by list endpoints. `sort_by` is free-form
+/// client input; see [`validate_sort_column`](crate::db::repositories::base::validate_sort_column)
+/// for how it's checked before use.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct FilterParams {
+    pub search: Option<String>,
+    pub sort_by: Option<String>,
+    pub order: Option<SortOrder>,
+}
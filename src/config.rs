@@ -0,0 +1,49 @@
+use std::env;
+
+/// Output format for the `tracing` subscriber configured in `server::run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable, colorized output for local development.
+    Pretty,
+    /// Bunyan-formatted JSON, one object per line, for log aggregators.
+    Json,
+}
+
+impl LogFormat {
+    fn from_env() -> Self {
+        match env::var("LOG_FORMAT").as_deref() {
+            Ok("json") => Self::Json,
+            _ => Self::Pretty,
+        }
+    }
+}
+
+/// Process configuration, loaded once at startup from the environment.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub host: String,
+    pub port: u16,
+    pub database_url: String,
+    pub jwt_secret: String,
+    pub jwt_expiry_seconds: i64,
+    pub log_format: LogFormat,
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        Self {
+            host: env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string()),
+            port: env::var("PORT")
+                .ok()
+                .and_then(|p| p.parse().ok())
+                .unwrap_or(8080),
+            database_url: env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
+            jwt_secret: env::var("JWT_SECRET").expect("JWT_SECRET must be set"),
+            jwt_expiry_seconds: env::var("JWT_EXPIRY_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            log_format: LogFormat::from_env(),
+        }
+    }
+}
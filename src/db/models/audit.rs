@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use diesel::{Identifiable, Queryable};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::base::BaseModel;
+use crate::schema::audit;
+
+/// The kind of mutation an audit row records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// One row of tamper-evident change history: who (`actor_id`) did what
+/// (`action`) to which entity (`entity_type`/`entity_id`), and the JSON
+/// diff of the fields that changed.
+#[derive(Debug, Clone, Queryable, Identifiable, Serialize, ToSchema)]
+#[diesel(table_name = audit)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub action: String,
+    pub changeset_json: Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl BaseModel for AuditEntry {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    // Audit rows are append-only; `created_at` doubles as `updated_at`.
+    fn updated_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime;
+use diesel::{Identifiable, Queryable, QueryableByName};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::schema::jobs;
+
+/// Lifecycle of a queued job row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Pending,
+    Running,
+    Failed,
+}
+
+impl JobState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl From<&str> for JobState {
+    fn from(value: &str) -> Self {
+        match value {
+            "running" => Self::Running,
+            "failed" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+/// A queued unit of deferred work. `payload_json` holds the serialized
+/// [`crate::jobs::Job`]; `attempts`/`run_at` drive the worker's
+/// exponential-backoff retry loop.
+#[derive(Debug, Clone, Queryable, QueryableByName, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = jobs)]
+pub struct JobRecord {
+    pub id: Uuid,
+    pub queue: String,
+    pub payload_json: Value,
+    pub state: String,
+    pub attempts: i32,
+    pub run_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+impl JobRecord {
+    pub fn state_enum(&self) -> JobState {
+        JobState::from(self.state.as_str())
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::NaiveDateTime;
+use diesel::{Identifiable, Queryable};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::base::BaseModel;
+use crate::schema::organizations;
+
+#[derive(Debug, Clone, Queryable, Identifiable, Serialize, ToSchema)]
+#[diesel(table_name = organizations)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl BaseModel for Organization {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> NaiveDateTime {
+        self.updated_at
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::NaiveDateTime;
+use diesel::{Identifiable, Queryable};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::base::BaseModel;
+use crate::schema::users;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+            Self::Member => "member",
+        }
+    }
+}
+
+impl From<&str> for Role {
+    fn from(value: &str) -> Self {
+        match value {
+            "admin" => Self::Admin,
+            _ => Self::Member,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Identifiable, Serialize, ToSchema)]
+#[diesel(table_name = users)]
+pub struct User {
+    pub id: Uuid,
+    pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl User {
+    pub fn role_enum(&self) -> Role {
+        Role::from(self.role.as_str())
+    }
+}
+
+impl BaseModel for User {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> NaiveDateTime {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_from_str_recognizes_admin() {
+        assert_eq!(Role::from("admin"), Role::Admin);
+    }
+
+    #[test]
+    fn role_from_str_defaults_unknown_values_to_member() {
+        assert_eq!(Role::from("member"), Role::Member);
+        assert_eq!(Role::from("whatever"), Role::Member);
+    }
+}
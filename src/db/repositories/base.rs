@@ -1,12 +1,19 @@
+use crate::api::types::pagination::FilterParams;
+use crate::error::common::database::DatabaseError;
 use crate::errors::AppResult;
-use diesel::PgConnection;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chrono::NaiveDateTime;
+use diesel::{Connection, ExpressionMethods, PgConnection, RunQueryDsl};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::db::models::audit::AuditAction;
 use crate::db::models::base::BaseModel;
+use crate::schema::audit;
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PaginationParams {
     pub page: i64,
     pub per_page: i64,
@@ -21,12 +28,275 @@ impl Default for PaginationParams {
     }
 }
 
+/// Opaque cursor carried between keyset pages: the `(created_at, id)` tuple
+/// of the last row returned, serialized as JSON and base64-encoded. The
+/// cursor is neither signed nor encrypted, so a client can decode or build one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Cursor {
+    created_at: NaiveDateTime,
+    id: Uuid,
+}
+
+impl Cursor {
+    fn encode(created_at: NaiveDateTime, id: Uuid) -> String {
+        let json = serde_json::json!({ "created_at": created_at, "id": id }).to_string();
+        STANDARD.encode(json)
+    }
+
+    fn decode(raw: &str) -> AppResult<Self> {
+        let bytes = STANDARD
+            .decode(raw)
+            .map_err(|e| DatabaseError::QueryFailed(format!("invalid cursor encoding: {e}")))?;
+        serde_json::from_slice(&bytes)
+            .map_err(|e| DatabaseError::QueryFailed(format!("invalid cursor payload: {e}")))
+            .map_err(Into::into)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CursorPaginationParams {
+    pub after: Option<String>,
+    pub per_page: i64,
+}
+
+impl Default for CursorPaginationParams {
+    fn default() -> Self {
+        Self {
+            after: None,
+            per_page: 10,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CursorPaginatedResponse<T> {
+    pub data: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl<T> CursorPaginatedResponse<T> {
+    /// Builds the page from a result set that was over-fetched by one row
+    /// (`per_page + 1`), trimming the lookahead row and deriving `has_more`
+    /// from its presence.
+    pub fn from_overfetch(mut rows: Vec<T>, per_page: i64, cursor_of: impl Fn(&T) -> (NaiveDateTime, Uuid)) -> Self {
+        let has_more = rows.len() as i64 > per_page;
+        if has_more {
+            rows.truncate(per_page as usize);
+        }
+        let next_cursor = rows
+            .last()
+            .map(|row| {
+                let (created_at, id) = cursor_of(row);
+                Cursor::encode(created_at, id)
+            })
+            .filter(|_| has_more);
+        Self {
+            data: rows,
+            next_cursor,
+            has_more,
+        }
+    }
+}
+
 pub trait BaseRepository<M: BaseModel> {
     fn find_by_id(conn: &mut PgConnection, id: Uuid) -> AppResult<M>;
     fn create(conn: &mut PgConnection, model: &M) -> AppResult<M>;
     fn update(conn: &mut PgConnection, id: Uuid, model: &M) -> AppResult<M>;
     fn soft_delete(conn: &mut PgConnection, id: Uuid) -> AppResult<M>;
     fn list(conn: &mut PgConnection, pagination: &PaginationParams) -> AppResult<Vec<M>>;
+
+    /// Keyset (cursor) variant of `list`. Orders on `(created_at, id)` descending
+    /// with `id` as a stable tie-breaker, so rows are neither skipped nor
+    /// duplicated by concurrent inserts the way offset pagination can be.
+    /// Fetches `per_page + 1` rows so `CursorPaginatedResponse` can compute
+    /// `has_more` without a separate count query.
+    fn list_keyset(
+        conn: &mut PgConnection,
+        pagination: &CursorPaginationParams,
+    ) -> AppResult<CursorPaginatedResponse<M>>;
+
+    /// `list`, but filtered by an `ILIKE '%term%'` search across the
+    /// implementor's searchable columns and sorted by `filter.sort_by`.
+    /// Implementors must pass `sort_by` through [`validate_sort_column`]
+    /// before building the query.
+    fn search(
+        conn: &mut PgConnection,
+        pagination: &PaginationParams,
+        filter: &FilterParams,
+    ) -> AppResult<(Vec<M>, i64)>;
+
+    /// Entity type recorded against audit rows written for this repository's
+    /// model, e.g. `"organization"`.
+    fn entity_type() -> &'static str;
+
+    /// `create`, but within the same transaction records an audit row
+    /// crediting `actor_id` with the created row as the changeset.
+    fn create_audited(conn: &mut PgConnection, model: &M, actor_id: Option<Uuid>) -> AppResult<M>
+    where
+        M: Serialize,
+    {
+        conn.transaction(|conn| {
+            let created = Self::create(conn, model)?;
+            record_audit_entry(
+                conn,
+                Self::entity_type(),
+                created.id(),
+                actor_id,
+                AuditAction::Create,
+                None,
+                &created,
+            )?;
+            Ok(created)
+        })
+    }
+
+    /// `update`, but within the same transaction records an audit row
+    /// diffing the row's state before and after the update.
+    fn update_audited(
+        conn: &mut PgConnection,
+        id: Uuid,
+        model: &M,
+        actor_id: Option<Uuid>,
+    ) -> AppResult<M>
+    where
+        M: Serialize,
+    {
+        conn.transaction(|conn| {
+            let before = Self::find_by_id(conn, id)?;
+            let updated = Self::update(conn, id, model)?;
+            record_audit_entry(
+                conn,
+                Self::entity_type(),
+                id,
+                actor_id,
+                AuditAction::Update,
+                Some(&before),
+                &updated,
+            )?;
+            Ok(updated)
+        })
+    }
+
+    /// `soft_delete`, but within the same transaction records an audit row
+    /// capturing the row's state immediately prior to deletion, and
+    /// enqueues [`purge_job_for`](Self::purge_job_for) if the model wants
+    /// its row permanently purged later.
+    fn soft_delete_audited(
+        conn: &mut PgConnection,
+        id: Uuid,
+        actor_id: Option<Uuid>,
+    ) -> AppResult<M>
+    where
+        M: Serialize,
+    {
+        conn.transaction(|conn| {
+            let before = Self::find_by_id(conn, id)?;
+            let deleted = Self::soft_delete(conn, id)?;
+            record_audit_entry(
+                conn,
+                Self::entity_type(),
+                id,
+                actor_id,
+                AuditAction::Delete,
+                Some(&before),
+                &deleted,
+            )?;
+            if let Some(job) = Self::purge_job_for(id) {
+                crate::jobs::enqueue(conn, job)?;
+            }
+            Ok(deleted)
+        })
+    }
+
+    /// Job to enqueue for deferred purging once `soft_delete_audited` has
+    /// run, e.g. `Some(Job::PurgeSoftDeleted { org_id: id })`. Defaults to
+    /// `None` — most models have nothing to purge.
+    fn purge_job_for(_id: Uuid) -> Option<crate::jobs::Job> {
+        None
+    }
+}
+
+/// Serializes `before`/`after` to JSON and inserts an audit row holding only
+/// the diff between them, so the stored changeset stays small and readable
+/// even for wide rows.
+fn record_audit_entry<M: Serialize>(
+    conn: &mut PgConnection,
+    entity_type: &'static str,
+    entity_id: Uuid,
+    actor_id: Option<Uuid>,
+    action: AuditAction,
+    before: Option<&M>,
+    after: &M,
+) -> AppResult<()> {
+    let changeset = diff_changeset(before, after)?;
+
+    diesel::insert_into(audit::table)
+        .values((
+            audit::id.eq(Uuid::new_v4()),
+            audit::actor_id.eq(actor_id),
+            audit::entity_type.eq(entity_type),
+            audit::entity_id.eq(entity_id),
+            audit::action.eq(action.as_str()),
+            audit::changeset_json.eq(changeset),
+        ))
+        .execute(conn)
+        .map_err(DatabaseError::from)?;
+
+    Ok(())
+}
+
+/// Diffs the serialized JSON of `before`/`after`, keeping only the
+/// top-level fields whose values differ. `before` is `None` for creates, in
+/// which case the full `after` row is stored.
+fn diff_changeset<M: Serialize>(before: Option<&M>, after: &M) -> AppResult<Value> {
+    let after_value = serde_json::to_value(after)
+        .map_err(|e| DatabaseError::QueryFailed(format!("failed to serialize changeset: {e}")))?;
+
+    let Some(before) = before else {
+        return Ok(serde_json::json!({ "after": after_value }));
+    };
+    let before_value = serde_json::to_value(before)
+        .map_err(|e| DatabaseError::QueryFailed(format!("failed to serialize changeset: {e}")))?;
+
+    let (Value::Object(before_map), Value::Object(after_map)) = (&before_value, &after_value) else {
+        return Ok(serde_json::json!({ "before": before_value, "after": after_value }));
+    };
+
+    let mut diff = Map::new();
+    for (key, after_field) in after_map {
+        if before_map.get(key) != Some(after_field) {
+            diff.insert(
+                key.clone(),
+                serde_json::json!({ "before": before_map.get(key), "after": after_field }),
+            );
+        }
+    }
+    Ok(Value::Object(diff))
+}
+
+/// Validates a client-supplied `sort_by` column name against `whitelist`,
+/// returning the `DatabaseError` the repository should surface on a miss
+/// rather than ever interpolating the raw value into a query.
+pub fn validate_sort_column<'a>(
+    sort_by: Option<&'a str>,
+    whitelist: &[&'a str],
+    default: &'a str,
+) -> AppResult<&'a str> {
+    match sort_by {
+        None => Ok(default),
+        Some(col) if whitelist.contains(&col) => Ok(col),
+        Some(col) => Err(DatabaseError::QueryFailed(format!("cannot sort by column: {col}")).into()),
+    }
+}
+
+/// Decodes an `after` cursor into the `(created_at, id)` tuple to filter on,
+/// returning `None` for the first page. Malformed cursors surface as a
+/// `DatabaseError::QueryFailed` via `Cursor::decode`.
+pub(crate) fn decode_after_cursor(after: Option<&str>) -> AppResult<Option<(NaiveDateTime, Uuid)>> {
+    after
+        .map(|raw| Cursor::decode(raw).map(|c| (c.created_at, c.id)))
+        .transpose()
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -60,4 +330,39 @@ impl<T> PaginatedResponse<T> {
             },
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips_through_encode_decode() {
+        let created_at = NaiveDateTime::parse_from_str("2026-01-15 12:30:00", "%Y-%m-%d %H:%M:%S")
+            .unwrap();
+        let id = Uuid::new_v4();
+
+        let encoded = Cursor::encode(created_at, id);
+        let decoded = Cursor::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.created_at, created_at);
+        assert_eq!(decoded.id, id);
+    }
+
+    #[test]
+    fn cursor_decode_rejects_malformed_input() {
+        assert!(Cursor::decode("not-base64!").is_err());
+        assert!(Cursor::decode(&STANDARD.encode("{}")).is_err());
+    }
+
+    #[test]
+    fn validate_sort_column_rejects_non_whitelisted_column() {
+        assert!(validate_sort_column(Some("password"), &["name", "created_at"], "created_at").is_err());
+    }
+
+    #[test]
+    fn validate_sort_column_passes_through_whitelisted_column() {
+        let column = validate_sort_column(Some("name"), &["name", "created_at"], "created_at").unwrap();
+        assert_eq!(column, "name");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,180 @@
+use diesel::pg::expression::extensions::PgTextExpressionMethods;
+use diesel::prelude::*;
+use diesel::PgConnection;
+use uuid::Uuid;
+
+use crate::api::types::organization::{ORGANIZATION_SEARCHABLE_COLUMNS, ORGANIZATION_SORTABLE_COLUMNS};
+use crate::api::types::pagination::{FilterParams, SortOrder};
+use crate::db::models::organization::Organization;
+use crate::error::common::database::DatabaseError;
+use crate::errors::AppResult;
+use crate::jobs::Job;
+use crate::schema::organizations;
+
+use super::base::{
+    decode_after_cursor, validate_sort_column, BaseRepository, CursorPaginatedResponse,
+    CursorPaginationParams, PaginationParams,
+};
+
+/// Diesel-backed `BaseRepository<Organization>`.
+pub struct PgOrganizationRepository;
+
+impl BaseRepository<Organization> for PgOrganizationRepository {
+    fn find_by_id(conn: &mut PgConnection, id: Uuid) -> AppResult<Organization> {
+        organizations::table
+            .filter(organizations::id.eq(id))
+            .filter(organizations::deleted_at.is_null())
+            .first(conn)
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+
+    fn create(conn: &mut PgConnection, model: &Organization) -> AppResult<Organization> {
+        diesel::insert_into(organizations::table)
+            .values((
+                organizations::id.eq(Uuid::new_v4()),
+                organizations::name.eq(&model.name),
+            ))
+            .get_result(conn)
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+
+    fn update(conn: &mut PgConnection, id: Uuid, model: &Organization) -> AppResult<Organization> {
+        diesel::update(organizations::table.filter(organizations::id.eq(id)))
+            .set((
+                organizations::name.eq(&model.name),
+                organizations::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .get_result(conn)
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+
+    fn soft_delete(conn: &mut PgConnection, id: Uuid) -> AppResult<Organization> {
+        diesel::update(organizations::table.filter(organizations::id.eq(id)))
+            .set(organizations::deleted_at.eq(Some(chrono::Utc::now().naive_utc())))
+            .get_result(conn)
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+
+    fn list(conn: &mut PgConnection, pagination: &PaginationParams) -> AppResult<Vec<Organization>> {
+        let offset = (pagination.page - 1) * pagination.per_page;
+        organizations::table
+            .filter(organizations::deleted_at.is_null())
+            .order(organizations::created_at.desc())
+            .limit(pagination.per_page)
+            .offset(offset)
+            .load(conn)
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+
+    fn list_keyset(
+        conn: &mut PgConnection,
+        pagination: &CursorPaginationParams,
+    ) -> AppResult<CursorPaginatedResponse<Organization>> {
+        let cursor = decode_after_cursor(pagination.after.as_deref())?;
+
+        let mut query = organizations::table
+            .filter(organizations::deleted_at.is_null())
+            .into_boxed();
+
+        if let Some((created_at, id)) = cursor {
+            query = query.filter(
+                organizations::created_at.lt(created_at).or(organizations::created_at
+                    .eq(created_at)
+                    .and(organizations::id.lt(id))),
+            );
+        }
+
+        let rows = query
+            .order((organizations::created_at.desc(), organizations::id.desc()))
+            .limit(pagination.per_page + 1)
+            .load::<Organization>(conn)
+            .map_err(DatabaseError::from)?;
+
+        Ok(CursorPaginatedResponse::from_overfetch(
+            rows,
+            pagination.per_page,
+            |row| (row.created_at, row.id),
+        ))
+    }
+
+    fn search(
+        conn: &mut PgConnection,
+        pagination: &PaginationParams,
+        filter: &FilterParams,
+    ) -> AppResult<(Vec<Organization>, i64)> {
+        let sort_column =
+            validate_sort_column(filter.sort_by.as_deref(), ORGANIZATION_SORTABLE_COLUMNS, "created_at")?;
+
+        let mut query = organizations::table
+            .filter(organizations::deleted_at.is_null())
+            .into_boxed();
+        let mut count_query = organizations::table
+            .filter(organizations::deleted_at.is_null())
+            .into_boxed();
+
+        if let Some(term) = &filter.search {
+            let pattern = format!("%{term}%");
+            // `name` is the only column in `ORGANIZATION_SEARCHABLE_COLUMNS`
+            // today, and the only one matched here; other entries of the
+            // whitelist are not searched by this query.
+            if ORGANIZATION_SEARCHABLE_COLUMNS.contains(&"name") {
+                query = query.filter(organizations::name.ilike(pattern.clone()));
+                count_query = count_query.filter(organizations::name.ilike(pattern));
+            }
+        }
+
+        // Match `list()`'s default ordering (newest first) when the caller
+        // doesn't specify one, rather than `SortOrder::default()`'s `Asc`.
+        let order = filter.order.unwrap_or(SortOrder::Desc);
+        query = match (sort_column, order) {
+            ("name", SortOrder::Asc) => query.order(organizations::name.asc()),
+            ("name", SortOrder::Desc) => query.order(organizations::name.desc()),
+            ("updated_at", SortOrder::Asc) => query.order(organizations::updated_at.asc()),
+            ("updated_at", SortOrder::Desc) => query.order(organizations::updated_at.desc()),
+            (_, SortOrder::Asc) => query.order(organizations::created_at.asc()),
+            (_, SortOrder::Desc) => query.order(organizations::created_at.desc()),
+        };
+
+        let offset = (pagination.page - 1) * pagination.per_page;
+        let rows = query
+            .limit(pagination.per_page)
+            .offset(offset)
+            .load::<Organization>(conn)
+            .map_err(DatabaseError::from)?;
+
+        let total = count_query
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(DatabaseError::from)?;
+
+        Ok((rows, total))
+    }
+
+    fn entity_type() -> &'static str {
+        "organization"
+    }
+
+    fn purge_job_for(id: Uuid) -> Option<Job> {
+        Some(Job::PurgeSoftDeleted { org_id: id })
+    }
+}
+
+impl PgOrganizationRepository {
+    /// Not part of `BaseRepository` (it isn't needed by any generic caller),
+    /// but used directly by `OrganizationService::create` to reject
+    /// duplicate names before insert.
+    pub fn find_by_name(conn: &mut PgConnection, name: &str) -> AppResult<Option<Organization>> {
+        organizations::table
+            .filter(organizations::name.eq(name))
+            .filter(organizations::deleted_at.is_null())
+            .first(conn)
+            .optional()
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::error::{ApiError, ErrorCode, ErrorContext};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum AuthError {
+    InvalidCredentials,
+    MissingToken,
+    TokenExpired,
+    TokenInvalid(String),
+    InsufficientRole { required: String },
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCredentials => write!(f, "invalid email or password"),
+            Self::MissingToken => write!(f, "missing bearer token"),
+            Self::TokenExpired => write!(f, "token has expired"),
+            Self::TokenInvalid(msg) => write!(f, "invalid token: {}", msg),
+            Self::InsufficientRole { required } => {
+                write!(f, "requires '{}' role", required)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+impl From<AuthError> for ApiError {
+    fn from(error: AuthError) -> Self {
+        let code = match &error {
+            AuthError::InsufficientRole { .. } => ErrorCode::Forbidden,
+            _ => ErrorCode::Unauthorized,
+        };
+
+        ApiError::new(
+            code,
+            error.to_string(),
+            ErrorContext::new().with_details(serde_json::json!({
+                "error_type": format!("{:?}", error)
+            })),
+        )
+        .with_source(error)
+    }
+}
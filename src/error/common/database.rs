@@ -1,7 +1,8 @@
 use crate::error::{ApiError, ErrorCode, ErrorContext};
+use actix_web::error::BlockingError;
 use serde::Serialize;
 use std::fmt;
-use diesel::result::Error as DieselError;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 
 #[derive(Debug, Serialize)]
 pub enum DatabaseError {
@@ -51,10 +52,30 @@ impl From<DatabaseError> for ApiError {
 
 impl std::error::Error for DatabaseError {}
 
+impl From<BlockingError> for DatabaseError {
+    /// `web::block`'s worker thread pool was shut down or cancelled the
+    /// task before it ran; there is no underlying Diesel error to report.
+    fn from(error: BlockingError) -> Self {
+        DatabaseError::PoolError(error.to_string())
+    }
+}
+
+impl From<DieselError> for ApiError {
+    /// Lets `conn.transaction(...)` closures return `AppResult` directly:
+    /// Diesel needs the closure's error type to convert from its own, which
+    /// this routes through the same mapping as an explicit `DatabaseError::from`.
+    fn from(error: DieselError) -> Self {
+        DatabaseError::from(error).into()
+    }
+}
+
 impl From<DieselError> for DatabaseError {
     fn from(error: DieselError) -> Self {
         match error {
             DieselError::NotFound => DatabaseError::RecordNotFound(error.to_string()),
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info) => {
+                DatabaseError::UniqueViolation(info.message().to_string())
+            }
             DieselError::DatabaseError(_, info) => DatabaseError::QueryFailed(info.message().to_string()),
             DieselError::RollbackTransaction => DatabaseError::TransactionFailed(error.to_string()),
             DieselError::AlreadyInTransaction => DatabaseError::TransactionFailed("Already in transaction".to_string()),
@@ -62,4 +83,62 @@ impl From<DieselError> for DatabaseError {
             _ => DatabaseError::QueryFailed(error.to_string()),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::result::DatabaseErrorInformation;
+
+    struct FakeDbErrorInfo(&'static str);
+
+    impl DatabaseErrorInformation for FakeDbErrorInfo {
+        fn message(&self) -> &str {
+            self.0
+        }
+        fn details(&self) -> Option<&str> {
+            None
+        }
+        fn hint(&self) -> Option<&str> {
+            None
+        }
+        fn table_name(&self) -> Option<&str> {
+            None
+        }
+        fn column_name(&self) -> Option<&str> {
+            None
+        }
+        fn constraint_name(&self) -> Option<&str> {
+            None
+        }
+        fn statement_position(&self) -> Option<i32> {
+            None
+        }
+    }
+
+    #[test]
+    fn unique_violation_maps_to_unique_violation_not_query_failed() {
+        let error = DieselError::DatabaseError(
+            DatabaseErrorKind::UniqueViolation,
+            Box::new(FakeDbErrorInfo("duplicate key value violates unique constraint")),
+        );
+
+        assert!(matches!(
+            DatabaseError::from(error),
+            DatabaseError::UniqueViolation(_)
+        ));
+    }
+
+    #[test]
+    fn other_database_error_kinds_still_map_to_query_failed() {
+        let error = DieselError::DatabaseError(
+            DatabaseErrorKind::ForeignKeyViolation,
+            Box::new(FakeDbErrorInfo("violates foreign key constraint")),
+        );
+
+        assert!(matches!(
+            DatabaseError::from(error),
+            DatabaseError::QueryFailed(_)
+        ));
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod auth;
+pub mod database;
+pub mod validation;
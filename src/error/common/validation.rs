@@ -0,0 +1,117 @@
+use crate::error::{ApiError, ErrorCode, ErrorContext};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use validator::ValidationErrors;
+
+/// One failed validation rule on one field, e.g. `name` failing `length`
+/// with `min`/`max` params.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub params: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidationError(pub Vec<FieldError>);
+
+impl ValidationError {
+    /// Flattens the `validator` crate's per-field error map into our own
+    /// list, preserving each rule's code and params.
+    pub fn from_validator(errors: ValidationErrors) -> Self {
+        let fields = errors
+            .field_errors()
+            .into_iter()
+            .flat_map(|(field, field_errors)| {
+                field_errors.iter().map(move |err| FieldError {
+                    field: field.to_string(),
+                    code: err.code.to_string(),
+                    params: Value::Object(
+                        err.params
+                            .iter()
+                            .map(|(k, v)| (k.to_string(), v.clone()))
+                            .collect(),
+                    ),
+                })
+            })
+            .collect();
+        Self(fields)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "validation failed for {} field(s)", self.0.len())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::Validate;
+
+    #[derive(Validate)]
+    struct Input {
+        #[validate(length(min = 2, max = 10))]
+        name: String,
+        #[validate(range(min = 1))]
+        age: i32,
+    }
+
+    #[test]
+    fn flattens_each_failed_rule_into_a_field_error() {
+        let input = Input {
+            name: "a".to_string(),
+            age: 0,
+        };
+        let errors = input.validate().expect_err("input is invalid");
+
+        let flattened = ValidationError::from_validator(errors);
+
+        assert_eq!(flattened.0.len(), 2);
+        assert!(flattened.0.iter().any(|e| e.field == "name" && e.code == "length"));
+        assert!(flattened.0.iter().any(|e| e.field == "age" && e.code == "range"));
+    }
+
+    #[test]
+    fn valid_input_produces_no_field_errors() {
+        let input = Input {
+            name: "valid".to_string(),
+            age: 5,
+        };
+
+        assert!(input.validate().is_ok());
+    }
+}
+
+impl From<ValidationError> for ApiError {
+    fn from(error: ValidationError) -> Self {
+        let mut by_field: Map<String, Value> = Map::new();
+        for field_error in &error.0 {
+            let mut rule = Map::new();
+            rule.insert("code".to_string(), Value::String(field_error.code.clone()));
+            if let Value::Object(params) = &field_error.params {
+                for (k, v) in params {
+                    rule.insert(k.clone(), v.clone());
+                }
+            }
+
+            by_field
+                .entry(field_error.field.clone())
+                .or_insert_with(|| Value::Array(Vec::new()))
+                .as_array_mut()
+                .expect("always inserted as an array above")
+                .push(Value::Object(rule));
+        }
+
+        ApiError::new(
+            ErrorCode::BadRequest,
+            "request validation failed".to_string(),
+            ErrorContext::new().with_details(Value::Object(by_field)),
+        )
+        .with_source(error)
+    }
+}
@@ -0,0 +1,122 @@
+pub mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use serde::Serialize;
+use std::fmt;
+use uuid::Uuid;
+
+use crate::middleware::request_tracing::current_request_id;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    ConnectionPoolError,
+    DatabaseError,
+    InternalError,
+}
+
+impl ErrorCode {
+    fn status_code(self) -> StatusCode {
+        match self {
+            Self::BadRequest => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::Conflict => StatusCode::CONFLICT,
+            Self::ConnectionPoolError | Self::DatabaseError | Self::InternalError => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+/// Additional machine-readable context attached to an `ApiError`, e.g. the
+/// per-field validation failures or the Diesel error variant that produced
+/// it. Serialized verbatim into the error response body.
+#[derive(Debug, Default, Serialize)]
+pub struct ErrorContext {
+    /// The id of the request this error was raised during, so the response
+    /// body can be matched back to its `http_request` tracing span. `None`
+    /// outside of request handling (e.g. in the job worker).
+    pub request_id: Option<Uuid>,
+    pub details: Option<serde_json::Value>,
+}
+
+impl ErrorContext {
+    pub fn new() -> Self {
+        Self {
+            request_id: current_request_id(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub context: ErrorContext,
+    #[serde(skip)]
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>, context: ErrorContext) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            context,
+            source: None,
+        }
+    }
+
+    /// Attaches the underlying error for logging, without exposing it in the
+    /// serialized response body.
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    /// Stamps `request_id` onto this error's context if it doesn't already
+    /// have one. Used by [`blocking`](crate::middleware::request_tracing::blocking)
+    /// to recover the id for errors built off the request's task.
+    pub fn with_request_id(mut self, request_id: Option<Uuid>) -> Self {
+        if self.context.request_id.is_none() {
+            self.context.request_id = request_id;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_ref().map(|e| e.as_ref() as _)
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        self.code.status_code()
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self)
+    }
+}
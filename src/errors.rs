@@ -0,0 +1,3 @@
+use crate::error::ApiError;
+
+pub type AppResult<T> = Result<T, ApiError>;
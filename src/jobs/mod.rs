@@ -0,0 +1,283 @@
+//! Durable job queue: handlers enqueue work as a row in the `jobs` table
+//! instead of doing it inline, and a background worker (spawned from
+//! [`crate::server::run`]) polls for due work and dispatches it.
+
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
+use diesel::sql_types::{Int8, Timestamp};
+use diesel::PgConnection;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tokio::sync::Notify;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::db::models::job::JobRecord;
+use crate::db::{get_connection, DbPool};
+use crate::error::common::database::DatabaseError;
+use crate::errors::AppResult;
+use crate::schema::jobs;
+
+/// A unit of deferred work. Serialized to `jobs.payload_json` under an
+/// internally-tagged `type` field, so new variants can be added without
+/// breaking rows already queued under the old shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Job {
+    /// Hard-deletes an organization previously marked with `soft_delete`.
+    PurgeSoftDeleted { org_id: Uuid },
+    /// Fires a webhook with an arbitrary JSON body, retried like any other job.
+    SendWebhook { url: String, body: serde_json::Value },
+}
+
+impl Job {
+    fn queue(&self) -> &'static str {
+        match self {
+            Self::PurgeSoftDeleted { .. } => "purge",
+            Self::SendWebhook { .. } => "webhooks",
+        }
+    }
+}
+
+const MAX_ATTEMPTS: i32 = 5;
+const BASE_BACKOFF_SECS: i64 = 2;
+const POLL_INTERVAL: StdDuration = StdDuration::from_secs(1);
+const BATCH_SIZE: i64 = 10;
+
+/// Enqueues `job` to run as soon as a worker picks it up. Takes `conn`
+/// rather than a pool so callers can enqueue atomically alongside the
+/// mutation that triggered it, e.g. within the same transaction as a
+/// `soft_delete`.
+pub fn enqueue(conn: &mut PgConnection, job: Job) -> AppResult<()> {
+    let payload = serde_json::to_value(&job)
+        .map_err(|e| DatabaseError::QueryFailed(format!("failed to serialize job: {e}")))?;
+
+    diesel::insert_into(jobs::table)
+        .values((
+            jobs::id.eq(Uuid::new_v4()),
+            jobs::queue.eq(job.queue()),
+            jobs::payload_json.eq(payload),
+            jobs::state.eq("pending"),
+            jobs::attempts.eq(0),
+            jobs::run_at.eq(Utc::now().naive_utc()),
+        ))
+        .execute(conn)
+        .map_err(DatabaseError::from)?;
+
+    Ok(())
+}
+
+/// Spawned once from [`crate::server::run`]; polls for due jobs until
+/// `shutdown` is notified, then returns so the caller can await it as part
+/// of graceful shutdown.
+pub async fn run_worker(pool: DbPool, shutdown: Arc<Notify>) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = shutdown.notified() => {
+                info!("job worker shutting down");
+                break;
+            }
+            _ = interval.tick() => {
+                if let Err(e) = poll_and_dispatch(&pool).await {
+                    error!("job worker poll failed: {e}");
+                }
+            }
+        }
+    }
+}
+
+async fn poll_and_dispatch(pool: &DbPool) -> AppResult<()> {
+    let claim_pool = pool.clone();
+    let due = actix_web::web::block(move || {
+        let mut conn = get_connection(&claim_pool)?;
+        claim_due_jobs(&mut conn, BATCH_SIZE)
+    })
+    .await
+    .map_err(DatabaseError::from)??;
+
+    for job_record in due {
+        let pool = pool.clone();
+        run_one(pool, job_record).await;
+    }
+
+    Ok(())
+}
+
+/// Locks up to `limit` due, pending jobs with `FOR UPDATE SKIP LOCKED` (so
+/// concurrent workers never double-process a row) and flips them to
+/// `running` in the same transaction.
+fn claim_due_jobs(conn: &mut PgConnection, limit: i64) -> AppResult<Vec<JobRecord>> {
+    conn.transaction(|conn| {
+        let claimed = diesel::sql_query(
+            "SELECT id, queue, payload_json, state, attempts, run_at, created_at \
+             FROM jobs \
+             WHERE state = 'pending' AND run_at <= $2 \
+             ORDER BY run_at \
+             LIMIT $1 \
+             FOR UPDATE SKIP LOCKED",
+        )
+        .bind::<Int8, _>(limit)
+        .bind::<Timestamp, _>(Utc::now().naive_utc())
+        .load::<JobRecord>(conn)?;
+
+        let ids: Vec<Uuid> = claimed.iter().map(|j| j.id).collect();
+        diesel::update(jobs::table.filter(jobs::id.eq_any(&ids)))
+            .set(jobs::state.eq("running"))
+            .execute(conn)?;
+
+        Ok(claimed)
+    })
+    .map_err(|e: diesel::result::Error| DatabaseError::from(e).into())
+}
+
+async fn run_one(pool: DbPool, job_record: JobRecord) {
+    let job: Job = match serde_json::from_value(job_record.payload_json.clone()) {
+        Ok(job) => job,
+        Err(e) => {
+            error!("job {} has an unparseable payload: {e}", job_record.id);
+            let _ = finalize(&pool, &job_record, Err(e.to_string())).await;
+            return;
+        }
+    };
+
+    let result = dispatch(&pool, job).await;
+    if let Err(e) = &result {
+        warn!("job {} failed (attempt {}): {e}", job_record.id, job_record.attempts + 1);
+    }
+    if let Err(e) = finalize(&pool, &job_record, result.map_err(|e| e.to_string())).await {
+        error!("failed to record outcome of job {}: {e}", job_record.id);
+    }
+}
+
+async fn dispatch(pool: &DbPool, job: Job) -> AppResult<()> {
+    match job {
+        Job::PurgeSoftDeleted { org_id } => {
+            let pool = pool.clone();
+            actix_web::web::block(move || {
+                let mut conn = get_connection(&pool)?;
+                purge_soft_deleted(&mut conn, org_id)
+            })
+            .await
+            .map_err(DatabaseError::from)??;
+        }
+        Job::SendWebhook { url, body } => {
+            send_webhook(&url, &body).await?;
+        }
+    }
+    Ok(())
+}
+
+fn purge_soft_deleted(conn: &mut PgConnection, org_id: Uuid) -> AppResult<()> {
+    use crate::schema::organizations;
+
+    diesel::delete(organizations::table.filter(organizations::id.eq(org_id)))
+        .execute(conn)
+        .map_err(DatabaseError::from)?;
+
+    Ok(())
+}
+
+async fn send_webhook(url: &str, body: &serde_json::Value) -> AppResult<()> {
+    reqwest::Client::new()
+        .post(url)
+        .json(body)
+        .send()
+        .await
+        .and_then(|res| res.error_for_status())
+        .map_err(|e| DatabaseError::QueryFailed(format!("webhook delivery failed: {e}")))?;
+
+    Ok(())
+}
+
+/// Pure outcome of retrying a job, split out from [`finalize`] so the
+/// backoff/max-attempts schedule is unit-testable without a database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RetryDecision {
+    /// The job succeeded; its row should be removed.
+    Succeeded,
+    /// `attempts` has reached `MAX_ATTEMPTS`; the job is marked `failed` and
+    /// will not be retried again.
+    Failed,
+    /// The job should be retried after `backoff_secs`.
+    Retry { backoff_secs: i64 },
+}
+
+/// Decides what should happen to a job that just ran, given its outcome and
+/// the number of attempts already recorded (i.e. before this attempt).
+fn decide_retry(result: &Result<(), String>, attempts: i32) -> RetryDecision {
+    match result {
+        Ok(()) => RetryDecision::Succeeded,
+        Err(_) if attempts + 1 >= MAX_ATTEMPTS => RetryDecision::Failed,
+        Err(_) => RetryDecision::Retry {
+            backoff_secs: BASE_BACKOFF_SECS.pow((attempts + 1) as u32),
+        },
+    }
+}
+
+/// On success, removes the job row. On failure, increments `attempts` and
+/// reschedules `run_at` with exponential backoff (`BASE_BACKOFF_SECS ^
+/// attempts`), or marks the job `failed` once `MAX_ATTEMPTS` is reached.
+async fn finalize(pool: &DbPool, job_record: &JobRecord, result: Result<(), String>) -> AppResult<()> {
+    let pool = pool.clone();
+    let job_id = job_record.id;
+    let attempts = job_record.attempts;
+    let decision = decide_retry(&result, attempts);
+
+    actix_web::web::block(move || {
+        let mut conn = get_connection(&pool)?;
+        match decision {
+            RetryDecision::Succeeded => {
+                diesel::delete(jobs::table.filter(jobs::id.eq(job_id))).execute(&mut conn)?;
+            }
+            RetryDecision::Failed => {
+                diesel::update(jobs::table.filter(jobs::id.eq(job_id)))
+                    .set((jobs::state.eq("failed"), jobs::attempts.eq(attempts + 1)))
+                    .execute(&mut conn)?;
+            }
+            RetryDecision::Retry { backoff_secs } => {
+                let backoff = Duration::seconds(backoff_secs);
+                diesel::update(jobs::table.filter(jobs::id.eq(job_id)))
+                    .set((
+                        jobs::state.eq("pending"),
+                        jobs::attempts.eq(attempts + 1),
+                        jobs::run_at.eq(Utc::now().naive_utc() + backoff),
+                    ))
+                    .execute(&mut conn)?;
+            }
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .map_err(DatabaseError::from)?
+    .map_err(DatabaseError::from)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn success_clears_the_job() {
+        assert_eq!(decide_retry(&Ok(()), 0), RetryDecision::Succeeded);
+        assert_eq!(decide_retry(&Ok(()), MAX_ATTEMPTS - 1), RetryDecision::Succeeded);
+    }
+
+    #[test]
+    fn failure_backs_off_exponentially() {
+        let err = Err("boom".to_string());
+        assert_eq!(decide_retry(&err, 0), RetryDecision::Retry { backoff_secs: 2 });
+        assert_eq!(decide_retry(&err, 1), RetryDecision::Retry { backoff_secs: 4 });
+        assert_eq!(decide_retry(&err, 2), RetryDecision::Retry { backoff_secs: 8 });
+    }
+
+    #[test]
+    fn failure_gives_up_at_max_attempts() {
+        let err = Err("boom".to_string());
+        assert_eq!(decide_retry(&err, MAX_ATTEMPTS - 1), RetryDecision::Failed);
+        assert_eq!(decide_retry(&err, MAX_ATTEMPTS), RetryDecision::Failed);
+    }
+}
@@ -0,0 +1,177 @@
+use actix_web::{dev::Payload, http::header, web, FromRequest, HttpRequest};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::future::{ready, Ready};
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::models::user::{Role, User};
+use crate::error::common::auth::AuthError;
+use crate::errors::AppResult;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: Uuid,
+    pub role: String,
+    pub exp: usize,
+}
+
+/// Signs a JWT for `user`, valid for `config.jwt_expiry_seconds`.
+pub fn issue_token(user: &User, config: &Config) -> AppResult<String> {
+    let exp = (Utc::now() + Duration::seconds(config.jwt_expiry_seconds)).timestamp() as usize;
+    let claims = Claims {
+        sub: user.id,
+        role: user.role.clone(),
+        exp,
+    };
+
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+    )
+    .map_err(|e| AuthError::TokenInvalid(e.to_string()).into())
+}
+
+/// Extractor that validates the `Authorization: Bearer <jwt>` header and
+/// resolves it to the authenticated user's id and role. Handlers that need
+/// an authenticated caller simply add `user: AuthedUser` as an argument;
+/// Actix runs the extractor before the handler body and rejects the request
+/// with 401 on a missing/invalid/expired token.
+#[derive(Debug, Clone)]
+pub struct AuthedUser {
+    pub user_id: Uuid,
+    pub role: Role,
+}
+
+impl AuthedUser {
+    /// Requires the caller to hold `required` (or `Role::Admin`, which can
+    /// act as any role), returning 403 via `AuthError::InsufficientRole`
+    /// otherwise.
+    pub fn require_role(&self, required: Role) -> AppResult<()> {
+        if self.role == required || self.role == Role::Admin {
+            Ok(())
+        } else {
+            Err(AuthError::InsufficientRole {
+                required: required.as_str().to_string(),
+            }
+            .into())
+        }
+    }
+
+    fn from_header(req: &HttpRequest) -> AppResult<Self> {
+        let config = req
+            .app_data::<web::Data<Config>>()
+            .expect("Config must be registered as app_data");
+
+        let header_value = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .ok_or(AuthError::MissingToken)?;
+        let header_str = header_value
+            .to_str()
+            .map_err(|_| AuthError::TokenInvalid("header is not valid ASCII".to_string()))?;
+        let token = header_str
+            .strip_prefix("Bearer ")
+            .ok_or(AuthError::MissingToken)?;
+
+        let decoded = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+            &Validation::new(Algorithm::HS256),
+        )
+        .map_err(|e| match e.kind() {
+            jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthError::TokenExpired,
+            _ => AuthError::TokenInvalid(e.to_string()),
+        })?;
+
+        Ok(Self {
+            user_id: decoded.claims.sub,
+            role: Role::from(decoded.claims.role.as_str()),
+        })
+    }
+}
+
+impl FromRequest for AuthedUser {
+    type Error = crate::error::ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Self::from_header(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCode;
+
+    fn config() -> Config {
+        Config {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+            database_url: String::new(),
+            jwt_secret: "test-secret".to_string(),
+            jwt_expiry_seconds: 3600,
+            log_format: crate::config::LogFormat::Pretty,
+        }
+    }
+
+    fn user(role: &str) -> User {
+        User {
+            id: Uuid::new_v4(),
+            email: "user@example.com".to_string(),
+            password_hash: String::new(),
+            role: role.to_string(),
+            created_at: Utc::now().naive_utc(),
+            updated_at: Utc::now().naive_utc(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn issue_token_round_trips_through_decode() {
+        let config = config();
+        let user = user("member");
+
+        let token = issue_token(&user, &config).unwrap();
+        let decoded = decode::<Claims>(
+            &token,
+            &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+            &Validation::new(Algorithm::HS256),
+        )
+        .unwrap();
+
+        assert_eq!(decoded.claims.sub, user.id);
+        assert_eq!(decoded.claims.role, "member");
+    }
+
+    #[test]
+    fn require_role_allows_matching_role() {
+        let authed = AuthedUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Member,
+        };
+        assert!(authed.require_role(Role::Member).is_ok());
+    }
+
+    #[test]
+    fn require_role_allows_admin_for_any_required_role() {
+        let authed = AuthedUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Admin,
+        };
+        assert!(authed.require_role(Role::Member).is_ok());
+    }
+
+    #[test]
+    fn require_role_rejects_lower_privileged_role() {
+        let authed = AuthedUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Member,
+        };
+        let err = authed.require_role(Role::Admin).unwrap_err();
+        assert_eq!(err.code, ErrorCode::Forbidden);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod auth;
+pub mod request_tracing;
+
+pub use auth::AuthedUser;
+pub use request_tracing::RequestTracing;
@@ -0,0 +1,146 @@
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::Error;
+use futures_util::future::LocalBoxFuture;
+use std::future::{ready, Ready};
+use std::time::Instant;
+use tracing::{info_span, Instrument};
+use uuid::Uuid;
+
+use crate::error::common::database::DatabaseError;
+use crate::errors::AppResult;
+
+tokio::task_local! {
+    static REQUEST_ID: Uuid;
+}
+
+/// The current request's id, if called from within a span opened by
+/// [`RequestTracing`]. Used by `ErrorContext::new` to stamp error responses
+/// with the id of the request that produced them, so a response body and
+/// its log lines can be correlated.
+pub fn current_request_id() -> Option<Uuid> {
+    REQUEST_ID.try_with(|id| *id).ok()
+}
+
+/// Runs `f` on the blocking thread pool via `actix_web::web::block`, then
+/// re-stamps the calling request's id onto any `ApiError` it returns.
+/// `web::block`'s closure runs on a separate blocking-pool thread that
+/// doesn't inherit this task's `REQUEST_ID`, so `ErrorContext::new()` (called
+/// by every conversion into `ApiError`) bakes in `request_id: None`
+/// for errors raised inside it. Handlers should call this instead of
+/// `web::block` directly so error responses stay correlatable with logs.
+pub async fn blocking<F, T>(f: F) -> AppResult<T>
+where
+    F: FnOnce() -> AppResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let request_id = current_request_id();
+    actix_web::web::block(f)
+        .await
+        .map_err(DatabaseError::from)?
+        .map_err(|e| e.with_request_id(request_id))
+}
+
+/// Opens a `tracing` span per request carrying a generated request id,
+/// method, and path; records the response status and latency once the
+/// inner service resolves, and echoes the id back as `x-request-id`.
+pub struct RequestTracing;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestTracing
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestTracingMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestTracingMiddleware { service }))
+    }
+}
+
+pub struct RequestTracingMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestTracingMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let request_id = Uuid::new_v4();
+        let method = req.method().clone();
+        let path = req.path().to_string();
+        let span = info_span!(
+            "http_request",
+            %request_id,
+            %method,
+            %path,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+
+        let started_at = Instant::now();
+        let fut = self.service.call(req);
+
+        let traced = REQUEST_ID.scope(request_id, async move {
+            let mut res = fut.await?;
+
+            let span = tracing::Span::current();
+            span.record("status", res.status().as_u16());
+            span.record("latency_ms", started_at.elapsed().as_millis() as u64);
+
+            if let Ok(value) = HeaderValue::from_str(&request_id.to_string()) {
+                res.headers_mut()
+                    .insert(HeaderName::from_static("x-request-id"), value);
+            }
+
+            Ok(res)
+        });
+
+        Box::pin(traced.instrument(span))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{ApiError, ErrorCode, ErrorContext};
+
+    #[test]
+    fn current_request_id_is_none_outside_a_request_scope() {
+        assert_eq!(current_request_id(), None);
+    }
+
+    #[actix_web::test]
+    async fn blocking_recovers_the_callers_request_id_onto_errors() {
+        let request_id = Uuid::new_v4();
+
+        let result = REQUEST_ID
+            .scope(request_id, async {
+                blocking(|| -> AppResult<()> {
+                    // Runs on a separate blocking-pool thread, so
+                    // `ErrorContext::new()` bakes in `request_id: None` here --
+                    // this is exactly the gap `blocking` patches back up.
+                    Err(ApiError::new(ErrorCode::InternalError, "boom", ErrorContext::new()))
+                })
+                .await
+            })
+            .await;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.context.request_id, Some(request_id));
+    }
+}
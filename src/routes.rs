@@ -1,19 +1,29 @@
-use crate::api::organization_api;
+use crate::api::{auth_api, organization_api};
 use actix_web::{web, Scope};
 
 pub fn api_routes() -> Scope {
-    web::scope("/api").service(
-        web::scope("/organizations")
-            .route("", web::post().to(organization_api::create_organization))
-            .route("/{id}", web::get().to(organization_api::get_organization))
-            .route(
-                "/{id}",
-                web::put().to(organization_api::update_organization),
-            )
-            .route(
-                "/{id}",
-                web::delete().to(organization_api::delete_organization),
-            )
-            .route("", web::get().to(organization_api::list_organizations)),
-    )
+    web::scope("/api")
+        .service(
+            web::scope("/organizations")
+                .route("", web::post().to(organization_api::create_organization))
+                .route("/{id}", web::get().to(organization_api::get_organization))
+                .route(
+                    "/{id}/history",
+                    web::get().to(organization_api::get_organization_history),
+                )
+                .route(
+                    "/{id}",
+                    web::put().to(organization_api::update_organization),
+                )
+                .route(
+                    "/{id}",
+                    web::delete().to(organization_api::delete_organization),
+                )
+                .route("", web::get().to(organization_api::list_organizations)),
+        )
+        .service(
+            web::scope("/auth")
+                .route("/register", web::post().to(auth_api::register))
+                .route("/login", web::post().to(auth_api::login)),
+        )
 }
@@ -1,22 +1,79 @@
 use crate::api::doc::ApiDoc;
-use crate::config::Config;
+use crate::config::{Config, LogFormat};
 use crate::db::DbPool;
+use crate::jobs;
+use crate::middleware::RequestTracing;
 use crate::routes;
 use actix_web::{web, App, HttpServer};
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tracing::info;
+use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
+use tracing_subscriber::{fmt, layer::SubscriberExt, EnvFilter, Registry};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+/// Configures the global `tracing` subscriber: an `EnvFilter` (`RUST_LOG`,
+/// defaulting to `info`), `config.log_format` choosing between a pretty
+/// formatter for local development and bunyan JSON for log aggregators, and
+/// a non-blocking writer so a slow log sink can never stall a worker thread.
+/// The returned guard flushes the writer's background thread on drop and
+/// must be kept alive for the process lifetime.
+fn init_tracing(config: &Config) -> tracing_appender::non_blocking::WorkerGuard {
+    let (non_blocking, guard) = tracing_appender::non_blocking(std::io::stdout());
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let registry = Registry::default().with(env_filter);
+
+    match config.log_format {
+        LogFormat::Json => {
+            let subscriber = registry
+                .with(JsonStorageLayer)
+                .with(BunyanFormattingLayer::new(
+                    "create_rust_server".into(),
+                    non_blocking,
+                ));
+            tracing::subscriber::set_global_default(subscriber)
+                .expect("failed to install tracing subscriber");
+        }
+        LogFormat::Pretty => {
+            let subscriber =
+                registry.with(fmt::layer().with_writer(non_blocking).pretty());
+            tracing::subscriber::set_global_default(subscriber)
+                .expect("failed to install tracing subscriber");
+        }
+    }
+
+    guard
+}
+
 pub async fn run(config: Config, pool: DbPool) -> std::io::Result<()> {
-    HttpServer::new(move || {
+    let _tracing_guard = init_tracing(&config);
+
+    let job_worker_shutdown = Arc::new(Notify::new());
+    let job_worker = actix_web::rt::spawn(jobs::run_worker(
+        pool.clone(),
+        job_worker_shutdown.clone(),
+    ));
+
+    let bind_addr = format!("{}:{}", config.host, config.port);
+    let result = HttpServer::new(move || {
         App::new()
+            .wrap(RequestTracing)
             .app_data(web::Data::new(pool.clone()))
+            .app_data(web::Data::new(config.clone()))
             .service(routes::api_routes())
             .service(
                 SwaggerUi::new("/swagger-ui/{_:.*}")
                     .url("/api-doc/openapi.json", ApiDoc::openapi()),
             )
     })
-    .bind(format!("{}:{}", config.host, config.port))?
+    .bind(bind_addr)?
     .run()
-    .await
+    .await;
+
+    info!("stopping job worker");
+    job_worker_shutdown.notify_one();
+    let _ = job_worker.await;
+
+    result
 }
@@ -0,0 +1,41 @@
+use diesel::prelude::*;
+use diesel::PgConnection;
+use uuid::Uuid;
+
+use crate::db::models::audit::AuditEntry;
+use crate::db::repositories::base::PaginationParams;
+use crate::error::common::database::DatabaseError;
+use crate::errors::AppResult;
+use crate::schema::audit;
+
+pub struct AuditService;
+
+impl AuditService {
+    /// Paginated change history for one entity, newest first.
+    pub fn history_for_entity(
+        conn: &mut PgConnection,
+        entity_type: &str,
+        entity_id: Uuid,
+        pagination: &PaginationParams,
+    ) -> AppResult<(Vec<AuditEntry>, i64)> {
+        let offset = (pagination.page - 1) * pagination.per_page;
+
+        let entries = audit::table
+            .filter(audit::entity_type.eq(entity_type))
+            .filter(audit::entity_id.eq(entity_id))
+            .order(audit::created_at.desc())
+            .limit(pagination.per_page)
+            .offset(offset)
+            .load::<AuditEntry>(conn)
+            .map_err(DatabaseError::from)?;
+
+        let total = audit::table
+            .filter(audit::entity_type.eq(entity_type))
+            .filter(audit::entity_id.eq(entity_id))
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(DatabaseError::from)?;
+
+        Ok((entries, total))
+    }
+}
@@ -0,0 +1,68 @@
+use bcrypt::{hash, verify, DEFAULT_COST};
+use diesel::prelude::*;
+use diesel::PgConnection;
+use uuid::Uuid;
+
+use crate::api::types::auth::{LoginInput, RegisterInput};
+use crate::config::Config;
+use crate::db::models::user::User;
+use crate::error::common::auth::AuthError;
+use crate::error::common::database::DatabaseError;
+use crate::errors::AppResult;
+use crate::middleware::auth::issue_token;
+use crate::schema::users;
+
+pub struct AuthService;
+
+impl AuthService {
+    /// Creates a user with a bcrypt-hashed password. Only the hash is ever
+    /// persisted or returned; the plaintext password never leaves this call.
+    pub fn register(conn: &mut PgConnection, input: RegisterInput) -> AppResult<User> {
+        let existing = users::table
+            .filter(users::email.eq(&input.email))
+            .filter(users::deleted_at.is_null())
+            .first::<User>(conn)
+            .optional()
+            .map_err(DatabaseError::from)?;
+        if existing.is_some() {
+            return Err(DatabaseError::UniqueViolation(format!(
+                "email already registered: {}",
+                input.email
+            ))
+            .into());
+        }
+
+        let password_hash = hash(&input.password, DEFAULT_COST)
+            .map_err(|e| DatabaseError::QueryFailed(format!("failed to hash password: {e}")))?;
+
+        diesel::insert_into(users::table)
+            .values((
+                users::id.eq(Uuid::new_v4()),
+                users::email.eq(&input.email),
+                users::password_hash.eq(&password_hash),
+                users::role.eq("member"),
+            ))
+            .get_result::<User>(conn)
+            .map_err(DatabaseError::from)
+            .map_err(Into::into)
+    }
+
+    /// Verifies credentials and, on success, issues a signed JWT.
+    pub fn login(conn: &mut PgConnection, input: LoginInput, config: &Config) -> AppResult<String> {
+        let user = users::table
+            .filter(users::email.eq(&input.email))
+            .filter(users::deleted_at.is_null())
+            .first::<User>(conn)
+            .optional()
+            .map_err(DatabaseError::from)?
+            .ok_or(AuthError::InvalidCredentials)?;
+
+        let valid = verify(&input.password, &user.password_hash)
+            .map_err(|e| DatabaseError::QueryFailed(format!("failed to verify password: {e}")))?;
+        if !valid {
+            return Err(AuthError::InvalidCredentials.into());
+        }
+
+        issue_token(&user, config)
+    }
+}
@@ -0,0 +1,89 @@
+use diesel::PgConnection;
+use uuid::Uuid;
+
+use crate::api::types::organization::{CreateOrganizationInput, UpdateOrganizationInput};
+use crate::api::types::pagination::FilterParams;
+use crate::db::models::organization::Organization;
+use crate::db::repositories::base::{
+    BaseRepository, CursorPaginatedResponse, CursorPaginationParams, PaginationParams,
+};
+use crate::db::repositories::organization::PgOrganizationRepository;
+use crate::error::common::database::DatabaseError;
+use crate::errors::AppResult;
+
+pub struct OrganizationService;
+
+impl OrganizationService {
+    pub fn find_by_id(conn: &mut PgConnection, id: Uuid) -> AppResult<Organization> {
+        PgOrganizationRepository::find_by_id(conn, id)
+    }
+
+    pub fn list(conn: &mut PgConnection, pagination: &PaginationParams) -> AppResult<Vec<Organization>> {
+        PgOrganizationRepository::list(conn, pagination)
+    }
+
+    pub fn list_keyset(
+        conn: &mut PgConnection,
+        pagination: &CursorPaginationParams,
+    ) -> AppResult<CursorPaginatedResponse<Organization>> {
+        PgOrganizationRepository::list_keyset(conn, pagination)
+    }
+
+    pub fn search(
+        conn: &mut PgConnection,
+        pagination: &PaginationParams,
+        filter: &FilterParams,
+    ) -> AppResult<(Vec<Organization>, i64)> {
+        PgOrganizationRepository::search(conn, pagination, filter)
+    }
+
+    /// Creates the organization and records an audit row crediting
+    /// `actor_id` with the new row, in the same transaction.
+    pub fn create(
+        conn: &mut PgConnection,
+        input: CreateOrganizationInput,
+        actor_id: Option<Uuid>,
+    ) -> AppResult<Organization> {
+        if PgOrganizationRepository::find_by_name(conn, &input.name)?.is_some() {
+            return Err(DatabaseError::UniqueViolation(format!(
+                "organization name already taken: {}",
+                input.name
+            ))
+            .into());
+        }
+
+        let draft = Organization {
+            id: Uuid::nil(),
+            name: input.name,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+            deleted_at: None,
+        };
+
+        PgOrganizationRepository::create_audited(conn, &draft, actor_id)
+    }
+
+    /// Updates the organization and records an audit row diffing its state
+    /// before/after, in the same transaction.
+    pub fn update(
+        conn: &mut PgConnection,
+        id: Uuid,
+        input: UpdateOrganizationInput,
+        actor_id: Option<Uuid>,
+    ) -> AppResult<Organization> {
+        let current = PgOrganizationRepository::find_by_id(conn, id)?;
+        let draft = Organization {
+            name: input.name.unwrap_or(current.name),
+            ..current
+        };
+
+        PgOrganizationRepository::update_audited(conn, id, &draft, actor_id)
+    }
+
+    /// Soft-deletes the organization, records an audit row capturing its
+    /// prior state, and enqueues the deferred purge job — all in the same
+    /// transaction, via `soft_delete_audited`.
+    pub fn delete(conn: &mut PgConnection, id: Uuid, actor_id: Option<Uuid>) -> AppResult<Organization> {
+        PgOrganizationRepository::soft_delete_audited(conn, id, actor_id)
+    }
+}